@@ -1,3 +1,6 @@
+use std::error;
+use std::fmt;
+
 /// The `CheckDigitAlgorithm` trait is used to specify the functionality of a checksum function
 /// that uses a check digit for error detection.
 pub trait CheckDigitAlgorithm {
@@ -9,6 +12,215 @@ pub trait CheckDigitAlgorithm {
 
     /// Tests if the provided number, which must be suffixed with a check digit, is erroneous.
     fn is_valid(&self, num: u64) -> bool;
+
+    /// Computes the checksum for the provided number given as a string.
+    ///
+    /// Unlike the `u64`-based methods, this preserves leading zeros and accepts grouped input
+    /// separated by ASCII spaces or hyphens (e.g. `"4111 1111 1111 1111"`).
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError>;
+
+    /// Computes the check digit for the provided number given as a string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError>;
+
+    /// Tests if the provided number string, which must be suffixed with a check digit, is
+    /// erroneous.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError>;
+
+    /// Validates a number string, which must be suffixed with a check digit, distinguishing a
+    /// malformed number from a mismatched check digit.
+    ///
+    /// Where `is_valid_str` only reports `true`/`false`, `validate` reports *why* a number is
+    /// invalid, and includes the expected check digit so callers can surface an actionable
+    /// message.
+    fn validate(&self, input: &str) -> Result<(), ChecksumError>;
+}
+
+/// The `CheckDigitsAlgorithm` trait mirrors `CheckDigitAlgorithm` for standards whose check value
+/// spans more than a single digit, such as the two-digit check characters used by ISO 7064
+/// MOD 97-10. A `u8` return type can't hold such a value, hence the separate trait rather than an
+/// awkward fit into `CheckDigitAlgorithm`.
+pub trait CheckDigitsAlgorithm {
+    /// Computes the checksum for the provided number.
+    fn checksum(&self, num: u64) -> u16;
+
+    /// Computes the check digits for the provided number.
+    fn calculate_check_digits(&self, num: u64) -> u16;
+
+    /// Tests if the provided number, which must be suffixed with its check digits, is erroneous.
+    fn is_valid(&self, num: u64) -> bool;
+
+    /// Computes the checksum for the provided number given as a string.
+    ///
+    /// Unlike the `u64`-based methods, this preserves leading zeros and accepts grouped input
+    /// separated by ASCII spaces or hyphens.
+    fn checksum_str(&self, s: &str) -> Result<u16, ParseError>;
+
+    /// Computes the check digits for the provided number given as a string.
+    fn calculate_check_digits_str(&self, s: &str) -> Result<u16, ParseError>;
+
+    /// Tests if the provided number string, which must be suffixed with its check digits, is
+    /// erroneous.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError>;
+
+    /// Validates a number string, which must be suffixed with its check digits, distinguishing a
+    /// malformed number from mismatched check digits.
+    fn validate(&self, input: &str) -> Result<(), ChecksumDigitsError>;
+}
+
+/// An error produced when parsing a string into a sequence of digits for a checksum computation.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input contained too few digits: fewer than two, or fewer than four for an algorithm
+    /// whose check value spans two digits.
+    TooShort,
+    /// The input contained a character the algorithm does not accept: for numeric input, anything
+    /// other than an ASCII digit or a separator (a space or a hyphen); for a VIN, anything other
+    /// than an allowed uppercase letter or digit.
+    InvalidCharacter(char),
+    /// The input did not have the length a fixed-length standard requires.
+    WrongLength {
+        /// The number of digits the standard requires.
+        expected: usize,
+        /// The number of digits actually found in the input.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::TooShort => write!(f, "input contains too few digits"),
+            ParseError::InvalidCharacter(c) => write!(f, "invalid character '{}' in input", c),
+            ParseError::WrongLength { expected, found } => {
+                write!(f, "input must contain {} digits, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl error::Error for ParseError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseError::TooShort => "input contains too few digits",
+            ParseError::InvalidCharacter(_) => "input contained a character that is not accepted",
+            ParseError::WrongLength { .. } => "input did not have the required number of digits",
+        }
+    }
+}
+
+/// Parses `s` into its sequence of digits, in the order they appear, skipping ASCII spaces and
+/// hyphens used as visual separators.
+fn parse_digits(s: &str) -> Result<Vec<u8>, ParseError> {
+    let mut digits: Vec<u8> = Vec::new();
+    for c in s.chars() {
+        if c == ' ' || c == '-' {
+            continue;
+        }
+        match c.to_digit(10) {
+            Some(d) => digits.push(d as u8),
+            None => return Err(ParseError::InvalidCharacter(c)),
+        }
+    }
+    if digits.len() < 2 {
+        return Err(ParseError::TooShort);
+    }
+    Ok(digits)
+}
+
+/// Splits a parsed digit sequence into its payload (all but the last digit) and the trailing
+/// check digit.
+fn split_check_digit(mut digits: Vec<u8>) -> (Vec<u8>, u8) {
+    let found = digits.pop().expect("parse_digits guarantees at least two digits");
+    (digits, found)
+}
+
+/// Decomposes a number into its digits, most significant digit first.
+fn digits_of(num: u64) -> Vec<u8> {
+    let mut digits: Vec<u8> = Vec::new();
+    let mut num_pre_div = num;
+    let mut num_post_div;
+    loop {
+        num_post_div = num_pre_div / 10;
+        let digit = num_pre_div - num_post_div * 10;
+        digits.push(digit as u8);
+        if num_post_div == 0 {
+            break;
+        }
+        num_pre_div = num_post_div;
+    }
+    digits.reverse();
+    digits
+}
+
+/// An error produced when validating a number string suffixed with a check digit.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChecksumError {
+    /// The input was not a well-formed number.
+    InvalidNumber(ParseError),
+    /// The check digit present in the input did not match the expected check digit.
+    InvalidChecksum {
+        /// The check digit the algorithm expected.
+        expected: u8,
+        /// The check digit actually present in the input.
+        found: u8,
+    },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChecksumError::InvalidNumber(ref e) => write!(f, "invalid number: {}", e),
+            ChecksumError::InvalidChecksum { expected, found } => {
+                write!(f, "invalid check digit: expected {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl error::Error for ChecksumError {
+    fn description(&self) -> &str {
+        match *self {
+            ChecksumError::InvalidNumber(_) => "input was not a well-formed number",
+            ChecksumError::InvalidChecksum { .. } => "check digit did not match the expected check digit",
+        }
+    }
+}
+
+/// An error produced when validating a number string suffixed with check digits that span more
+/// than a single digit. Mirrors `ChecksumError` for `CheckDigitsAlgorithm` implementors.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ChecksumDigitsError {
+    /// The input was not a well-formed number.
+    InvalidNumber(ParseError),
+    /// The check digits present in the input did not match the expected check digits.
+    InvalidChecksum {
+        /// The check digits the algorithm expected.
+        expected: u16,
+        /// The check digits actually present in the input.
+        found: u16,
+    },
+}
+
+impl fmt::Display for ChecksumDigitsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ChecksumDigitsError::InvalidNumber(ref e) => write!(f, "invalid number: {}", e),
+            ChecksumDigitsError::InvalidChecksum { expected, found } => {
+                write!(f, "invalid check digits: expected {:02}, found {:02}", expected, found)
+            }
+        }
+    }
+}
+
+impl error::Error for ChecksumDigitsError {
+    fn description(&self) -> &str {
+        match *self {
+            ChecksumDigitsError::InvalidNumber(_) => "input was not a well-formed number",
+            ChecksumDigitsError::InvalidChecksum { .. } => {
+                "check digits did not match the expected check digits"
+            }
+        }
+    }
 }
 
 /// Luhn check digit algorithm.
@@ -49,6 +261,28 @@ impl LuhnAlgorithm {
         }
         sum
     }
+
+    /// Performs the summation of a digit sequence as specified by the Luhn algorithm.
+    ///
+    /// `digits` is ordered most significant digit first, as it would appear when read.
+    fn digit_sum_digits(digits: &[u8]) -> u32 {
+        let mut sum = 0u32;
+        // Scan digits from right to left
+        for (i, &digit) in digits.iter().rev().enumerate() {
+            if (i + 1) % 2 == 0 {
+                // Even indexed digits are doubled and adjusted if greater than 9
+                let mut second_digit = digit * 2;
+                if second_digit > 9 {
+                    second_digit = second_digit - 9;
+                }
+                sum += second_digit as u32;
+            } else {
+                // Odd indexed digits are treated as-is
+                sum += digit as u32;
+            }
+        }
+        sum
+    }
 }
 
 impl CheckDigitAlgorithm for LuhnAlgorithm {
@@ -110,6 +344,64 @@ impl CheckDigitAlgorithm for LuhnAlgorithm {
     fn is_valid(&self, num: u64) -> bool {
         self.checksum(num) == 0
     }
+
+    /// Computes the Luhn checksum for the provided number string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ParseError};
+    /// let algo = checksum::LuhnAlgorithm {};
+    /// let checksum = algo.checksum_str("4111 1111 1111 1111").unwrap();
+    /// assert_eq!(checksum, 0);
+    /// assert_eq!(algo.checksum_str("4111-1111-1111-111A"), Err(ParseError::InvalidCharacter('A')));
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = parse_digits(s)?;
+        Ok((LuhnAlgorithm::digit_sum_digits(&digits) % 10) as u8)
+    }
+
+    /// Computes the Luhn check digit for the provided number string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let mut digits = parse_digits(s)?;
+        digits.push(0);
+        Ok((LuhnAlgorithm::digit_sum_digits(&digits) * 9 % 10) as u8)
+    }
+
+    /// Verifies the check digit using the Luhn algorithm on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates a number string using the Luhn algorithm, reporting the expected check digit on
+    /// mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::LuhnAlgorithm {};
+    /// assert_eq!(algo.validate("79927398713"), Ok(()));
+    /// assert_eq!(
+    ///     algo.validate("79927398710"),
+    ///     Err(ChecksumError::InvalidChecksum { expected: 3, found: 0 })
+    /// );
+    /// assert_eq!(
+    ///     algo.validate("799?7398713"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::InvalidCharacter('?')))
+    /// );
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input).map_err(ChecksumError::InvalidNumber)?;
+        let (mut payload, found) = split_check_digit(digits);
+        payload.push(0);
+        let expected = (LuhnAlgorithm::digit_sum_digits(&payload) * 9 % 10) as u8;
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
 }
 
 /// Verhoeff check digit algorithm.
@@ -137,6 +429,20 @@ const VERHOEFF_P_TABLE: [[u8; 10]; 8] = [[0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
                                          [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
                                          [7, 0, 4, 6, 9, 1, 3, 2, 5, 8]];
 
+impl VerhoeffAlgorithm {
+    /// Computes the Verhoeff checksum for a digit sequence.
+    ///
+    /// `digits` is ordered most significant digit first, as it would appear when read.
+    fn checksum_digits(digits: &[u8]) -> u8 {
+        let mut c = 0u8;
+        // Scan digits from right to left
+        for (i, &digit) in digits.iter().rev().enumerate() {
+            c = VERHOEFF_D_TABLE[c as usize][VERHOEFF_P_TABLE[(i % 8) as usize][digit as usize] as usize];
+        }
+        c
+    }
+}
+
 impl CheckDigitAlgorithm for VerhoeffAlgorithm {
     /// Computes the Verhoeff checksum for the provided number.
     ///
@@ -203,4 +509,877 @@ impl CheckDigitAlgorithm for VerhoeffAlgorithm {
     fn is_valid(&self, num: u64) -> bool {
         self.checksum(num) == 0
     }
+
+    /// Computes the Verhoeff checksum for the provided number string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ParseError};
+    /// let algo = checksum::VerhoeffAlgorithm {};
+    /// let checksum = algo.checksum_str("2363").unwrap();
+    /// assert_eq!(checksum, 0);
+    /// assert_eq!(algo.checksum_str("2"), Err(ParseError::TooShort));
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = parse_digits(s)?;
+        Ok(VerhoeffAlgorithm::checksum_digits(&digits))
+    }
+
+    /// Computes the Verhoeff check digit for the provided number string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let mut digits = parse_digits(s)?;
+        digits.push(0);
+        let c = VerhoeffAlgorithm::checksum_digits(&digits);
+        Ok(VERHOEFF_INV_D_TABLE[c as usize])
+    }
+
+    /// Uses the Verhoeff checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates a number string using the Verhoeff algorithm, reporting the expected check
+    /// digit on mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::VerhoeffAlgorithm {};
+    /// assert_eq!(algo.validate("2363"), Ok(()));
+    /// assert_eq!(
+    ///     algo.validate("2360"),
+    ///     Err(ChecksumError::InvalidChecksum { expected: 3, found: 0 })
+    /// );
+    /// assert_eq!(algo.validate("2"), Err(ChecksumError::InvalidNumber(ParseError::TooShort)));
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input).map_err(ChecksumError::InvalidNumber)?;
+        let (mut payload, found) = split_check_digit(digits);
+        payload.push(0);
+        let c = VerhoeffAlgorithm::checksum_digits(&payload);
+        let expected = VERHOEFF_INV_D_TABLE[c as usize];
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Damm check digit algorithm.
+///
+/// Like the Verhoeff algorithm, the Damm algorithm detects all single-digit errors and all
+/// adjacent transposition errors. Unlike Verhoeff, it needs only a single 10x10 quasigroup table
+/// and no permutation or inverse tables, making it simpler and faster to evaluate.
+pub struct DammAlgorithm {}
+
+const DAMM_D_TABLE: [[u8; 10]; 10] = [[0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+                                      [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+                                      [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+                                      [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+                                      [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+                                      [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+                                      [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+                                      [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+                                      [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+                                      [2, 5, 8, 1, 4, 3, 6, 7, 9, 0]];
+
+impl DammAlgorithm {
+    /// Computes the Damm checksum for a digit sequence, most significant digit first.
+    fn checksum_digits(digits: &[u8]) -> u8 {
+        let mut interim = 0u8;
+        for &digit in digits {
+            interim = DAMM_D_TABLE[interim as usize][digit as usize];
+        }
+        interim
+    }
+}
+
+impl CheckDigitAlgorithm for DammAlgorithm {
+    /// Computes the Damm checksum for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// Generate a checksum for 5724:
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let num = 5724;
+    /// let algo = checksum::DammAlgorithm {};
+    /// let checksum = algo.checksum(num);
+    /// assert_eq!(checksum, 0);
+    /// ```
+    fn checksum(&self, num: u64) -> u8 {
+        let digits = digits_of(num);
+        DammAlgorithm::checksum_digits(&digits)
+    }
+
+    /// Computes the Damm check digit for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// Generate a check digit for 572:
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let num = 572;
+    /// let algo = checksum::DammAlgorithm {};
+    /// let check_digit = algo.calculate_check_digit(num);
+    /// assert_eq!(check_digit, 4);
+    /// ```
+    fn calculate_check_digit(&self, num: u64) -> u8 {
+        // The table's diagonal is all zeros, so the check digit that drives the running total
+        // back to zero is exactly the running total itself; no inverse lookup is needed.
+        self.checksum(num)
+    }
+
+    /// Uses the Damm checksum formula for error detection.
+    ///
+    /// # Examples
+    ///
+    /// Validate the check digit 5724.
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let num = 5724;
+    /// let algo = checksum::DammAlgorithm {};
+    /// let is_valid = algo.is_valid(num);
+    /// assert_eq!(is_valid, true);
+    /// ```
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 0
+    }
+
+    /// Computes the Damm checksum for the provided number string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ParseError};
+    /// let algo = checksum::DammAlgorithm {};
+    /// let checksum = algo.checksum_str("5724").unwrap();
+    /// assert_eq!(checksum, 0);
+    /// assert_eq!(algo.checksum_str("57?4"), Err(ParseError::InvalidCharacter('?')));
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = parse_digits(s)?;
+        Ok(DammAlgorithm::checksum_digits(&digits))
+    }
+
+    /// Computes the Damm check digit for the provided number string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        self.checksum_str(s)
+    }
+
+    /// Uses the Damm checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates a number string using the Damm algorithm, reporting the expected check digit on
+    /// mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::DammAlgorithm {};
+    /// assert_eq!(algo.validate("5724"), Ok(()));
+    /// assert_eq!(
+    ///     algo.validate("5720"),
+    ///     Err(ChecksumError::InvalidChecksum { expected: 4, found: 0 })
+    /// );
+    /// assert_eq!(
+    ///     algo.validate("57#4"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::InvalidCharacter('#')))
+    /// );
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input).map_err(ChecksumError::InvalidNumber)?;
+        let (payload, found) = split_check_digit(digits);
+        let expected = DammAlgorithm::checksum_digits(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Computes a weighted digit sum: the sum of `digits[i] * weights[i]` over every position.
+fn weighted_sum(digits: &[u8], weights: &[u8]) -> u32 {
+    digits.iter().zip(weights.iter()).map(|(&d, &w)| d as u32 * w as u32).sum()
+}
+
+/// Builds the alternating 1, 3, 1, 3, ... weight pattern used by the modulus-10 standards below,
+/// assigning weight 1 to the rightmost position of `len` digits.
+fn mod10_weights(len: usize) -> Vec<u8> {
+    (0..len).map(|i| if (len - 1 - i).is_multiple_of(2) { 1 } else { 3 }).collect()
+}
+
+/// Computes the modulus-10 weighted checksum for a full digit sequence, including its check
+/// digit. This is shared by the ISBN-13, EAN-13, and UPC standards, which differ only in length.
+fn mod10_checksum(digits: &[u8]) -> u8 {
+    let weights = mod10_weights(digits.len());
+    (weighted_sum(digits, &weights) % 10) as u8
+}
+
+/// Computes the modulus-10 check digit for `payload`, the full number without its check digit.
+fn mod10_check_digit(payload: &[u8]) -> u8 {
+    let mut extended = payload.to_vec();
+    extended.push(0);
+    let weights = mod10_weights(extended.len());
+    let sum = weighted_sum(&extended, &weights);
+    ((10 - (sum % 10)) % 10) as u8
+}
+
+/// ISBN-10 position weights, most significant digit first: weight 10 for the first digit, down
+/// to weight 1 for the tenth (check) digit.
+const ISBN10_WEIGHTS: [u8; 10] = [10, 9, 8, 7, 6, 5, 4, 3, 2, 1];
+
+/// Computes the modulus-11 weighted sum for an ISBN-10 digit sequence, aligned so the last digit
+/// always carries weight 1. Sequences longer than `ISBN10_WEIGHTS` can't be a valid ISBN-10
+/// regardless, so only the trailing digits that fit the table are weighted, which keeps this safe
+/// to call with a `u64`-derived digit sequence of any length.
+fn mod11_weighted_sum(digits: &[u8]) -> u32 {
+    let len = digits.len().min(ISBN10_WEIGHTS.len());
+    let weights = &ISBN10_WEIGHTS[ISBN10_WEIGHTS.len() - len..];
+    weighted_sum(&digits[digits.len() - len..], weights)
+}
+
+/// Computes the modulus-11 checksum for a full ISBN-10 digit sequence, including its check digit.
+fn mod11_checksum(digits: &[u8]) -> u8 {
+    (mod11_weighted_sum(digits) % 11) as u8
+}
+
+/// Computes the modulus-11 check digit for `payload`, the nine digits of an ISBN-10 without its
+/// check digit. A return value of `10` represents the check character `X`.
+fn mod11_check_digit(payload: &[u8]) -> u8 {
+    let mut extended = payload.to_vec();
+    extended.push(0);
+    let sum = mod11_weighted_sum(&extended);
+    ((11 - (sum % 11)) % 11) as u8
+}
+
+/// Parses an ISBN-10 string into its sequence of digit values, in the order they appear, skipping
+/// ASCII spaces and hyphens. The final character may be `X` (or `x`), representing the value 10.
+fn parse_isbn10_digits(s: &str) -> Result<Vec<u8>, ParseError> {
+    let chars: Vec<char> = s.chars().filter(|&c| c != ' ' && c != '-').collect();
+    let mut digits: Vec<u8> = Vec::with_capacity(chars.len());
+    let last_index = chars.len().saturating_sub(1);
+    for (i, &c) in chars.iter().enumerate() {
+        if i == last_index && (c == 'X' || c == 'x') {
+            digits.push(10);
+            continue;
+        }
+        match c.to_digit(10) {
+            Some(d) => digits.push(d as u8),
+            None => return Err(ParseError::InvalidCharacter(c)),
+        }
+    }
+    if digits.len() < 2 {
+        return Err(ParseError::TooShort);
+    }
+    Ok(digits)
+}
+
+/// Rejects a digit sequence that does not have exactly `expected` digits, as required by
+/// fixed-length standards such as ISBN-10.
+fn require_length(digits: Vec<u8>, expected: usize) -> Result<Vec<u8>, ParseError> {
+    if digits.len() != expected {
+        return Err(ParseError::WrongLength { expected, found: digits.len() });
+    }
+    Ok(digits)
+}
+
+/// Weighted modulus-10 check digit algorithm for ISBN-13 identifiers.
+///
+/// Applies alternating weights 1, 3, 1, 3, ... right to left over the thirteen digits; the check
+/// digit is chosen so the full, weighted sum is a multiple of 10.
+pub struct Isbn13Algorithm {}
+
+impl CheckDigitAlgorithm for Isbn13Algorithm {
+    /// Computes the ISBN-13 checksum for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn13Algorithm {};
+    /// assert_eq!(algo.checksum(9780306406157), 0);
+    /// ```
+    fn checksum(&self, num: u64) -> u8 {
+        mod10_checksum(&digits_of(num))
+    }
+
+    /// Computes the ISBN-13 check digit for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn13Algorithm {};
+    /// assert_eq!(algo.calculate_check_digit(978030640615), 7);
+    /// ```
+    fn calculate_check_digit(&self, num: u64) -> u8 {
+        mod10_check_digit(&digits_of(num))
+    }
+
+    /// Uses the ISBN-13 checksum formula for error detection.
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 0
+    }
+
+    /// Computes the ISBN-13 checksum for the provided number string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn13Algorithm {};
+    /// assert_eq!(algo.checksum_str("978-0-306-40615-7").unwrap(), 0);
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 13)?;
+        Ok(mod10_checksum(&digits))
+    }
+
+    /// Computes the ISBN-13 check digit for the provided number string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn13Algorithm {};
+    /// assert_eq!(algo.calculate_check_digit_str("978-0-306-40615").unwrap(), 7);
+    /// assert_eq!(algo.calculate_check_digit_str("97803064061").unwrap_err(), checksum::ParseError::WrongLength { expected: 12, found: 11 });
+    /// ```
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 12)?;
+        Ok(mod10_check_digit(&digits))
+    }
+
+    /// Uses the ISBN-13 checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates an ISBN-13 number string, reporting the expected check digit on mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::Isbn13Algorithm {};
+    /// assert_eq!(
+    ///     algo.validate("978030640615"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::WrongLength { expected: 13, found: 12 }))
+    /// );
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input)
+            .and_then(|d| require_length(d, 13))
+            .map_err(ChecksumError::InvalidNumber)?;
+        let (payload, found) = split_check_digit(digits);
+        let expected = mod10_check_digit(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Weighted modulus-10 check digit algorithm for EAN-13 identifiers.
+///
+/// Computationally identical to [`Isbn13Algorithm`] (an ISBN-13 is itself a registered EAN-13
+/// range); kept as a distinct type so callers can express which standard their input follows.
+pub struct Ean13Algorithm {}
+
+impl CheckDigitAlgorithm for Ean13Algorithm {
+    /// Computes the EAN-13 checksum for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Ean13Algorithm {};
+    /// assert_eq!(algo.checksum(4006381333931), 0);
+    /// ```
+    fn checksum(&self, num: u64) -> u8 {
+        mod10_checksum(&digits_of(num))
+    }
+
+    /// Computes the EAN-13 check digit for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Ean13Algorithm {};
+    /// assert_eq!(algo.calculate_check_digit(400638133393), 1);
+    /// ```
+    fn calculate_check_digit(&self, num: u64) -> u8 {
+        mod10_check_digit(&digits_of(num))
+    }
+
+    /// Uses the EAN-13 checksum formula for error detection.
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 0
+    }
+
+    /// Computes the EAN-13 checksum for the provided number string.
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 13)?;
+        Ok(mod10_checksum(&digits))
+    }
+
+    /// Computes the EAN-13 check digit for the provided number string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 12)?;
+        Ok(mod10_check_digit(&digits))
+    }
+
+    /// Uses the EAN-13 checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates an EAN-13 number string, reporting the expected check digit on mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::Ean13Algorithm {};
+    /// assert_eq!(
+    ///     algo.validate("400638133393"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::WrongLength { expected: 13, found: 12 }))
+    /// );
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input)
+            .and_then(|d| require_length(d, 13))
+            .map_err(ChecksumError::InvalidNumber)?;
+        let (payload, found) = split_check_digit(digits);
+        let expected = mod10_check_digit(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Weighted modulus-10 check digit algorithm for UPC-A identifiers.
+pub struct UpcAlgorithm {}
+
+impl CheckDigitAlgorithm for UpcAlgorithm {
+    /// Computes the UPC-A checksum for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::UpcAlgorithm {};
+    /// assert_eq!(algo.checksum(36000291452), 0);
+    /// ```
+    fn checksum(&self, num: u64) -> u8 {
+        mod10_checksum(&digits_of(num))
+    }
+
+    /// Computes the UPC-A check digit for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::UpcAlgorithm {};
+    /// assert_eq!(algo.calculate_check_digit(3600029145), 2);
+    /// ```
+    fn calculate_check_digit(&self, num: u64) -> u8 {
+        mod10_check_digit(&digits_of(num))
+    }
+
+    /// Uses the UPC-A checksum formula for error detection.
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 0
+    }
+
+    /// Computes the UPC-A checksum for the provided number string.
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 12)?;
+        Ok(mod10_checksum(&digits))
+    }
+
+    /// Computes the UPC-A check digit for the provided number string.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_digits(s)?, 11)?;
+        Ok(mod10_check_digit(&digits))
+    }
+
+    /// Uses the UPC-A checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates a UPC-A number string, reporting the expected check digit on mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{CheckDigitAlgorithm, ChecksumError, ParseError};
+    /// let algo = checksum::UpcAlgorithm {};
+    /// assert_eq!(
+    ///     algo.validate("4006381333931"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::WrongLength { expected: 12, found: 13 }))
+    /// );
+    /// ```
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_digits(input)
+            .and_then(|d| require_length(d, 12))
+            .map_err(ChecksumError::InvalidNumber)?;
+        let (payload, found) = split_check_digit(digits);
+        let expected = mod10_check_digit(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Weighted modulus-11 check digit algorithm for ISBN-10 identifiers.
+///
+/// Applies descending weights 10, 9, ..., 2 to the first nine digits; the tenth (check) digit is
+/// chosen so the full, weighted sum is a multiple of 11. Because that can require a remainder of
+/// 10, the check digit is conventionally written as `X`; the string-accepting methods accept that
+/// character as the last one of the input. Every method, string-based or `u64`-based, reports that
+/// check digit as the numeric value 10; none of them returns the character `X`.
+pub struct Isbn10Algorithm {}
+
+impl CheckDigitAlgorithm for Isbn10Algorithm {
+    /// Computes the ISBN-10 checksum for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn10Algorithm {};
+    /// assert_eq!(algo.checksum(306406152), 0);
+    /// ```
+    fn checksum(&self, num: u64) -> u8 {
+        mod11_checksum(&digits_of(num))
+    }
+
+    /// Computes the ISBN-10 check digit for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn10Algorithm {};
+    /// assert_eq!(algo.calculate_check_digit(30640615), 2);
+    /// ```
+    fn calculate_check_digit(&self, num: u64) -> u8 {
+        mod11_check_digit(&digits_of(num))
+    }
+
+    /// Uses the ISBN-10 checksum formula for error detection.
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 0
+    }
+
+    /// Computes the ISBN-10 checksum for the provided number string, accepting a trailing `X`
+    /// check character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitAlgorithm;
+    /// let algo = checksum::Isbn10Algorithm {};
+    /// assert_eq!(algo.checksum_str("0-8044-2957-X").unwrap(), 0);
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_isbn10_digits(s)?, 10)?;
+        Ok(mod11_checksum(&digits))
+    }
+
+    /// Computes the ISBN-10 check digit for the provided number string, returning 10 to represent
+    /// the check character `X`.
+    fn calculate_check_digit_str(&self, s: &str) -> Result<u8, ParseError> {
+        let digits = require_length(parse_isbn10_digits(s)?, 9)?;
+        Ok(mod11_check_digit(&digits))
+    }
+
+    /// Uses the ISBN-10 checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 0)
+    }
+
+    /// Validates an ISBN-10 number string, reporting the expected check digit on mismatch (10
+    /// represents the check character `X`).
+    fn validate(&self, input: &str) -> Result<(), ChecksumError> {
+        let digits = parse_isbn10_digits(input)
+            .and_then(|d| require_length(d, 10))
+            .map_err(ChecksumError::InvalidNumber)?;
+        let (payload, found) = split_check_digit(digits);
+        let expected = mod11_check_digit(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Computes the ISO 7064 MOD 97-10 remainder for a digit sequence, folding digits in one at a
+/// time (`rem = (rem * 10 + digit) % 97`) so arbitrarily long inputs never overflow.
+fn mod97_remainder(digits: &[u8]) -> u16 {
+    let mut rem: u32 = 0;
+    for &digit in digits {
+        rem = (rem * 10 + digit as u32) % 97;
+    }
+    rem as u16
+}
+
+/// Computes the two-digit ISO 7064 MOD 97-10 check value for `payload`, the digits of the number
+/// without its check digits.
+fn mod97_check_digits(payload: &[u8]) -> u16 {
+    let mut extended = payload.to_vec();
+    extended.push(0);
+    extended.push(0);
+    let rem = mod97_remainder(&extended);
+    98 - rem
+}
+
+/// Splits a parsed digit sequence into its payload and its trailing two-digit check value.
+fn split_check_digits(mut digits: Vec<u8>) -> Result<(Vec<u8>, u16), ParseError> {
+    if digits.len() < 4 {
+        return Err(ParseError::TooShort);
+    }
+    let ones = digits.pop().expect("length checked above");
+    let tens = digits.pop().expect("length checked above");
+    Ok((digits, tens as u16 * 10 + ones as u16))
+}
+
+/// ISO 7064 MOD 97-10 check digits algorithm, as used by IBAN and similar identifiers.
+///
+/// Unlike the single check digit produced by the other algorithms in this crate, MOD 97-10
+/// produces a two-digit (02-98) check value, so it implements `CheckDigitsAlgorithm` rather than
+/// `CheckDigitAlgorithm`. A number, including its check digits, is valid when the whole string
+/// reduces to a remainder of 1 modulo 97.
+pub struct Mod97Algorithm {}
+
+impl CheckDigitsAlgorithm for Mod97Algorithm {
+    /// Computes the MOD 97-10 remainder for the provided number, including its check digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitsAlgorithm;
+    /// let algo = checksum::Mod97Algorithm {};
+    /// assert_eq!(algo.checksum(123456751), 1);
+    /// ```
+    fn checksum(&self, num: u64) -> u16 {
+        mod97_remainder(&digits_of(num))
+    }
+
+    /// Computes the MOD 97-10 check digits for the provided number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitsAlgorithm;
+    /// let algo = checksum::Mod97Algorithm {};
+    /// assert_eq!(algo.calculate_check_digits(1234567), 51);
+    /// ```
+    fn calculate_check_digits(&self, num: u64) -> u16 {
+        mod97_check_digits(&digits_of(num))
+    }
+
+    /// Uses the MOD 97-10 checksum formula for error detection.
+    fn is_valid(&self, num: u64) -> bool {
+        self.checksum(num) == 1
+    }
+
+    /// Computes the MOD 97-10 remainder for the provided number string, including its check
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::CheckDigitsAlgorithm;
+    /// let algo = checksum::Mod97Algorithm {};
+    /// assert_eq!(algo.checksum_str("123456751").unwrap(), 1);
+    /// ```
+    fn checksum_str(&self, s: &str) -> Result<u16, ParseError> {
+        let digits = parse_digits(s)?;
+        Ok(mod97_remainder(&digits))
+    }
+
+    /// Computes the MOD 97-10 check digits for the provided number string.
+    fn calculate_check_digits_str(&self, s: &str) -> Result<u16, ParseError> {
+        let digits = parse_digits(s)?;
+        Ok(mod97_check_digits(&digits))
+    }
+
+    /// Uses the MOD 97-10 checksum formula for error detection on a number string.
+    fn is_valid_str(&self, s: &str) -> Result<bool, ParseError> {
+        Ok(self.checksum_str(s)? == 1)
+    }
+
+    /// Validates a number string, reporting the expected check digits on mismatch.
+    fn validate(&self, input: &str) -> Result<(), ChecksumDigitsError> {
+        let digits = parse_digits(input).map_err(ChecksumDigitsError::InvalidNumber)?;
+        let (payload, found) =
+            split_check_digits(digits).map_err(ChecksumDigitsError::InvalidNumber)?;
+        let expected = mod97_check_digits(&payload);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumDigitsError::InvalidChecksum { expected, found })
+        }
+    }
+}
+
+/// Position weights for the VIN check digit, most significant position first. Position 9 (index
+/// 8), the check digit itself, carries weight 0 so it drops out of its own computation.
+const VIN_WEIGHTS: [u8; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Transliterates a single VIN character into its numeric value. Digits map to themselves, and
+/// letters map per the standard table below; `I`, `O`, and `Q` are disallowed, since they are
+/// easily confused with `1` and `0`.
+fn vin_transliterate(c: char) -> Option<u8> {
+    match c {
+        '0'..='9' => c.to_digit(10).map(|d| d as u8),
+        'A' | 'J' => Some(1),
+        'B' | 'K' | 'S' => Some(2),
+        'C' | 'L' | 'T' => Some(3),
+        'D' | 'M' | 'U' => Some(4),
+        'E' | 'N' | 'V' => Some(5),
+        'F' | 'W' => Some(6),
+        'G' | 'P' | 'X' => Some(7),
+        'H' | 'Y' => Some(8),
+        'R' | 'Z' => Some(9),
+        _ => None,
+    }
+}
+
+/// Parses a 17-character VIN into its transliterated values, rejecting the wrong length or any
+/// disallowed character (`I`, `O`, `Q`, or anything that isn't an uppercase letter or digit)
+/// before any checksum arithmetic is attempted. Position 9 (index 8), the check digit itself, is
+/// a digit or the check character `X` (representing 10), not a transliterated letter.
+fn parse_vin(vin: &str) -> Result<Vec<u8>, ParseError> {
+    let chars: Vec<char> = vin.chars().collect();
+    if chars.len() != 17 {
+        return Err(ParseError::WrongLength { expected: 17, found: chars.len() });
+    }
+    chars
+        .into_iter()
+        .enumerate()
+        .map(|(i, c)| {
+            if i == 8 {
+                match c {
+                    '0'..='9' => c.to_digit(10).map(|d| d as u8),
+                    'X' => Some(10),
+                    _ => None,
+                }
+                .ok_or(ParseError::InvalidCharacter(c))
+            } else {
+                vin_transliterate(c).ok_or(ParseError::InvalidCharacter(c))
+            }
+        })
+        .collect()
+}
+
+/// Computes the VIN checksum (the weighted sum of transliterated characters, modulo 11) for a
+/// full 17-character sequence of transliterated values, including its own check digit.
+fn vin_checksum(values: &[u8]) -> u8 {
+    (weighted_sum(values, &VIN_WEIGHTS) % 11) as u8
+}
+
+/// Mod-11 check digit algorithm for 17-character Vehicle Identification Numbers.
+///
+/// Unlike the other algorithms in this crate, a VIN's check character (position 9) is computed
+/// over transliterated letters rather than plain digits, so `VinAlgorithm` takes strings directly
+/// instead of implementing `CheckDigitAlgorithm`'s `u64`-based interface.
+pub struct VinAlgorithm {}
+
+impl VinAlgorithm {
+    /// Computes the VIN checksum for the provided 17-character VIN, including its check digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::ParseError;
+    /// let algo = checksum::VinAlgorithm {};
+    /// assert_eq!(algo.checksum("1M8GDM9AXKP042788").unwrap(), 10);
+    /// assert_eq!(
+    ///     algo.checksum("1M8GDM9AXKP04278"),
+    ///     Err(ParseError::WrongLength { expected: 17, found: 16 })
+    /// );
+    /// ```
+    pub fn checksum(&self, vin: &str) -> Result<u8, ParseError> {
+        let values = parse_vin(vin)?;
+        Ok(vin_checksum(&values))
+    }
+
+    /// Computes the VIN check digit (0-9, or 10 for the check character `X`) for the provided
+    /// 17-character VIN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let algo = checksum::VinAlgorithm {};
+    /// assert_eq!(algo.calculate_check_digit("1M8GDM9AXKP042788").unwrap(), 10);
+    /// ```
+    pub fn calculate_check_digit(&self, vin: &str) -> Result<u8, ParseError> {
+        self.checksum(vin)
+    }
+
+    /// Uses the VIN checksum formula for error detection: position 9 of a valid VIN always
+    /// reproduces the checksum computed over the whole VIN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let algo = checksum::VinAlgorithm {};
+    /// assert!(algo.is_valid("1M8GDM9AXKP042788").unwrap());
+    /// assert!(!algo.is_valid("1M8GDM9A1KP042788").unwrap());
+    /// ```
+    pub fn is_valid(&self, vin: &str) -> Result<bool, ParseError> {
+        let values = parse_vin(vin)?;
+        let found = values[8];
+        Ok(vin_checksum(&values) == found)
+    }
+
+    /// Validates a VIN, reporting the expected check digit on mismatch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use checksum::{ChecksumError, ParseError};
+    /// let algo = checksum::VinAlgorithm {};
+    /// assert_eq!(
+    ///     algo.validate("1M8GDM9A1KP042788"),
+    ///     Err(ChecksumError::InvalidChecksum { expected: 10, found: 1 })
+    /// );
+    /// assert_eq!(
+    ///     algo.validate("1M8GDI9AXKP042788"),
+    ///     Err(ChecksumError::InvalidNumber(ParseError::InvalidCharacter('I')))
+    /// );
+    /// ```
+    pub fn validate(&self, vin: &str) -> Result<(), ChecksumError> {
+        let values = parse_vin(vin).map_err(ChecksumError::InvalidNumber)?;
+        let found = values[8];
+        let expected = vin_checksum(&values);
+        if expected == found {
+            Ok(())
+        } else {
+            Err(ChecksumError::InvalidChecksum { expected, found })
+        }
+    }
 }
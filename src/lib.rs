@@ -1,7 +1,16 @@
 mod checksum;
 
 pub use self::checksum::CheckDigitAlgorithm;
+pub use self::checksum::CheckDigitsAlgorithm;
+pub use self::checksum::ParseError;
+pub use self::checksum::ChecksumError;
+pub use self::checksum::ChecksumDigitsError;
 pub use self::checksum::LuhnAlgorithm;
-pub use self::checksum::verhoeff_checksum;
-pub use self::checksum::verhoeff_calculate_check_digit;
-pub use self::checksum::verhoeff_is_valid;
+pub use self::checksum::VerhoeffAlgorithm;
+pub use self::checksum::DammAlgorithm;
+pub use self::checksum::Isbn10Algorithm;
+pub use self::checksum::Isbn13Algorithm;
+pub use self::checksum::Ean13Algorithm;
+pub use self::checksum::UpcAlgorithm;
+pub use self::checksum::Mod97Algorithm;
+pub use self::checksum::VinAlgorithm;